@@ -1,116 +1,349 @@
+use std::any::Any;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+
 use arrow::array::Array as _;
-use arrow::array::{ArrayRef, StringArray};
-use arrow::datatypes::DataType;
+use arrow::array::{
+    ArrayRef, GenericStringArray, GenericStringBuilder, Int64Array, ListArray, ListBuilder,
+    OffsetSizeTrait, StringArray, StringBuilder, StringViewArray, StringViewBuilder,
+};
+use arrow::datatypes::{DataType, Field};
 
 use datafusion_common::{DataFusionError, Result};
 use datafusion_expr::{
-    create_udf, ColumnarValue, ScalarUDF, Volatility,
+    ColumnarValue, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
 };
 
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 
-#[derive(Default)]
+#[derive(Debug, Default)]
 struct RegexCache {
-    inner: Mutex<HashMap<String, Regex>>,
+    inner: Mutex<HashMap<(String, String), Regex>>,
 }
 
 impl RegexCache {
-    fn get(&self, pattern: &str) -> Result<Regex> {
+    fn get(&self, pattern: &str, flags: &str) -> Result<Regex> {
+        let key = (pattern.to_string(), flags.to_string());
+
         let mut guard = self
             .inner
             .lock()
             .map_err(|_| DataFusionError::Execution("Regex cache mutex poisoned".into()))?;
 
-        if let Some(r) = guard.get(pattern) {
+        if let Some(r) = guard.get(&key) {
             return Ok(r.clone());
         }
 
-        let compiled = Regex::new(pattern).map_err(|e| {
+        let mut builder = RegexBuilder::new(pattern);
+        apply_flags(&mut builder, flags)?;
+        let compiled = builder.build().map_err(|e| {
             DataFusionError::Execution(format!("Invalid regex pattern: {pattern}: {e}"))
         })?;
 
-        guard.insert(pattern.to_string(), compiled.clone());
+        guard.insert(key, compiled.clone());
         Ok(compiled)
     }
 }
 
-/// Create and return a DataFusion ScalarUDF implementing Spark-like regexp_extract.
-pub fn regexp_extract_udf() -> ScalarUDF {
-    let cache = Arc::new(RegexCache::default());
-
-    let fun = {
-        let cache = cache.clone();
+/// Apply Spark/DataFusion-style inline regex flags (`i`, `m`, `s`, `x`, `U`) to
+/// a `RegexBuilder`, erroring on any character that isn't a recognized flag.
+fn apply_flags(builder: &mut RegexBuilder, flags: &str) -> Result<()> {
+    for c in flags.chars() {
+        match c {
+            'i' => {
+                builder.case_insensitive(true);
+            }
+            'm' => {
+                builder.multi_line(true);
+            }
+            's' => {
+                builder.dot_matches_new_line(true);
+            }
+            'x' => {
+                builder.ignore_whitespace(true);
+            }
+            'U' => {
+                builder.swap_greed(true);
+            }
+            other => {
+                return Err(DataFusionError::Execution(format!(
+                    "regexp_extract: unknown flag character '{other}' in flags {flags:?}; \
+                     supported flags are i, m, s, x, U"
+                )))
+            }
+        }
+    }
+    Ok(())
+}
 
-        Arc::new(move |args: &[ColumnarValue]| -> Result<ColumnarValue> {
-            if args.len() != 3 {
-                return Err(DataFusionError::Execution(
-                    "regexp_extract expects 3 arguments: (str, pattern, idx)".into(),
-                ));
-            }
-
-            let s = &args[0];
-            let pattern = &args[1];
-            let idx = &args[2];
-
-            // Helper: get scalar string
-            let scalar_str = |v: &ColumnarValue, name: &str| -> Result<String> {
-                match v {
-                    ColumnarValue::Scalar(sv) => match sv {
-                        datafusion_common::ScalarValue::Utf8(Some(x)) => Ok(x.clone()),
-                        datafusion_common::ScalarValue::Utf8(None) => Err(
-                            DataFusionError::Execution(format!("{name} must not be NULL"))
-                        ),
-                        _ => Err(DataFusionError::Execution(format!(
-                            "{name} must be Utf8 scalar"
-                        ))),
-                    },
-                    _ => Err(DataFusionError::Execution(format!(
-                        "{name} must be a scalar in this implementation"
-                    ))),
-                }
-            };
+// Helper: get scalar string
+fn scalar_str(v: &ColumnarValue, name: &str) -> Result<String> {
+    match v {
+        ColumnarValue::Scalar(sv) => match sv {
+            datafusion_common::ScalarValue::Utf8(Some(x)) => Ok(x.clone()),
+            datafusion_common::ScalarValue::Utf8(None) => Err(DataFusionError::Execution(
+                format!("{name} must not be NULL"),
+            )),
+            _ => Err(DataFusionError::Execution(format!(
+                "{name} must be Utf8 scalar"
+            ))),
+        },
+        _ => Err(DataFusionError::Execution(format!(
+            "{name} must be a scalar in this implementation"
+        ))),
+    }
+}
 
-            // Helper: get scalar i64
-            let scalar_i64 = |v: &ColumnarValue, name: &str| -> Result<i64> {
-                match v {
-                    ColumnarValue::Scalar(sv) => match sv {
-                        datafusion_common::ScalarValue::Int64(Some(x)) => Ok(*x),
-                        datafusion_common::ScalarValue::Int64(None) => Err(
-                            DataFusionError::Execution(format!("{name} must not be NULL"))
-                        ),
-                        _ => Err(DataFusionError::Execution(format!(
-                            "{name} must be Int64 scalar"
-                        ))),
-                    },
-                    _ => Err(DataFusionError::Execution(format!(
-                        "{name} must be a scalar in this implementation"
-                    ))),
-                }
-            };
+// Helper: get scalar i64
+fn scalar_i64(v: &ColumnarValue, name: &str) -> Result<i64> {
+    match v {
+        ColumnarValue::Scalar(sv) => match sv {
+            datafusion_common::ScalarValue::Int64(Some(x)) => Ok(*x),
+            datafusion_common::ScalarValue::Int64(None) => Err(DataFusionError::Execution(
+                format!("{name} must not be NULL"),
+            )),
+            _ => Err(DataFusionError::Execution(format!(
+                "{name} must be Int64 scalar"
+            ))),
+        },
+        _ => Err(DataFusionError::Execution(format!(
+            "{name} must be a scalar in this implementation"
+        ))),
+    }
+}
 
-            let pattern = scalar_str(pattern, "pattern")?;
-            let idx = scalar_i64(idx, "idx")?;
+/// Which capture group `regexp_extract` should pull out of a match: a
+/// numbered group (`idx` given as `Int64`) or a named group (`idx` given as
+/// `Utf8`, e.g. for `(?P<year>\d{4})`).
+enum GroupRef {
+    Number(i64),
+    Name(String),
+}
 
-            if idx < 0 {
+impl GroupRef {
+    fn validate(self) -> Result<Self> {
+        if let GroupRef::Number(n) = &self {
+            if *n < 0 {
                 return Err(DataFusionError::Execution(format!(
-                    "regexp_extract group index must be non-negative, got {idx}"
+                    "regexp_extract group index must be non-negative, got {n}"
                 )));
             }
-            let idx_usize: usize = idx as usize;
+        }
+        Ok(self)
+    }
+}
 
-            let re = cache.get(&pattern)?;
+// Helper: get scalar idx, either a group number (Int64) or a group name (Utf8)
+fn scalar_group_ref(v: &ColumnarValue) -> Result<GroupRef> {
+    match v {
+        ColumnarValue::Scalar(sv) => match sv {
+            datafusion_common::ScalarValue::Int64(Some(n)) => GroupRef::Number(*n).validate(),
+            datafusion_common::ScalarValue::Int64(None) => {
+                Err(DataFusionError::Execution("idx must not be NULL".into()))
+            }
+            datafusion_common::ScalarValue::Utf8(Some(name)) => {
+                Ok(GroupRef::Name(name.clone()))
+            }
+            datafusion_common::ScalarValue::Utf8(None) => {
+                Err(DataFusionError::Execution("idx must not be NULL".into()))
+            }
+            _ => Err(DataFusionError::Execution(
+                "idx must be an Int64 or Utf8 scalar".into(),
+            )),
+        },
+        _ => Err(DataFusionError::Execution(
+            "idx must be a scalar in this implementation".into(),
+        )),
+    }
+}
 
+/// Resolve the `pattern` argument for row `i`, returning `Ok(None)` when that
+/// row's pattern is NULL (scalar or array) rather than erroring.
+fn row_pattern(v: &ColumnarValue, i: usize) -> Result<Option<String>> {
+    match v {
+        ColumnarValue::Scalar(datafusion_common::ScalarValue::Utf8(opt)) => Ok(opt.clone()),
+        ColumnarValue::Scalar(_) => Err(DataFusionError::Execution(
+            "pattern must be Utf8".into(),
+        )),
+        ColumnarValue::Array(arr) => {
+            let arr = arr.as_any().downcast_ref::<StringArray>().ok_or_else(|| {
+                DataFusionError::Execution("pattern array must be Utf8".into())
+            })?;
+            Ok(if arr.is_null(i) {
+                None
+            } else {
+                Some(arr.value(i).to_string())
+            })
+        }
+    }
+}
+
+/// Resolve the `idx` argument for row `i` into a `GroupRef`, returning
+/// `Ok(None)` when that row's idx is NULL (scalar or array) rather than
+/// erroring.
+fn row_group_ref(v: &ColumnarValue, i: usize) -> Result<Option<GroupRef>> {
+    match v {
+        ColumnarValue::Scalar(datafusion_common::ScalarValue::Int64(opt)) => opt
+            .map(|n| GroupRef::Number(n).validate())
+            .transpose(),
+        ColumnarValue::Scalar(datafusion_common::ScalarValue::Utf8(opt)) => {
+            Ok(opt.clone().map(GroupRef::Name))
+        }
+        ColumnarValue::Scalar(_) => Err(DataFusionError::Execution(
+            "idx must be Int64 or Utf8".into(),
+        )),
+        ColumnarValue::Array(arr) => {
+            if let Some(arr) = arr.as_any().downcast_ref::<Int64Array>() {
+                if arr.is_null(i) {
+                    Ok(None)
+                } else {
+                    Some(GroupRef::Number(arr.value(i)).validate()).transpose()
+                }
+            } else if let Some(arr) = arr.as_any().downcast_ref::<StringArray>() {
+                Ok(if arr.is_null(i) {
+                    None
+                } else {
+                    Some(GroupRef::Name(arr.value(i).to_string()))
+                })
+            } else {
+                Err(DataFusionError::Execution(
+                    "idx array must be Int64 or Utf8".into(),
+                ))
+            }
+        }
+    }
+}
+
+fn regexp_extract_signature() -> Signature {
+    Signature::one_of(
+        vec![
+            TypeSignature::Exact(vec![DataType::Utf8, DataType::Utf8, DataType::Int64]),
+            TypeSignature::Exact(vec![DataType::LargeUtf8, DataType::Utf8, DataType::Int64]),
+            TypeSignature::Exact(vec![DataType::Utf8View, DataType::Utf8, DataType::Int64]),
+            TypeSignature::Exact(vec![DataType::Utf8, DataType::Utf8, DataType::Utf8]),
+            TypeSignature::Exact(vec![DataType::LargeUtf8, DataType::Utf8, DataType::Utf8]),
+            TypeSignature::Exact(vec![DataType::Utf8View, DataType::Utf8, DataType::Utf8]),
+            TypeSignature::Exact(vec![
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Int64,
+                DataType::Utf8,
+            ]),
+            TypeSignature::Exact(vec![
+                DataType::LargeUtf8,
+                DataType::Utf8,
+                DataType::Int64,
+                DataType::Utf8,
+            ]),
+            TypeSignature::Exact(vec![
+                DataType::Utf8View,
+                DataType::Utf8,
+                DataType::Int64,
+                DataType::Utf8,
+            ]),
+            TypeSignature::Exact(vec![
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Utf8,
+            ]),
+            TypeSignature::Exact(vec![
+                DataType::LargeUtf8,
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Utf8,
+            ]),
+            TypeSignature::Exact(vec![
+                DataType::Utf8View,
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Utf8,
+            ]),
+        ],
+        Volatility::Immutable,
+    )
+}
+
+/// Spark-like `regexp_extract(str, pattern, idx[, flags])`.
+///
+/// `str` may be `Utf8`, `LargeUtf8`, or `Utf8View`; the returned array uses
+/// whichever of those three layouts was passed in, so callers never pay for a
+/// forced cast just to run the regex. An optional 4th scalar `flags` argument
+/// (e.g. `"im"`) maps onto `regex::RegexBuilder` settings. `idx` may be an
+/// `Int64` group number or a `Utf8` capture-group name (e.g. `"year"` for
+/// `(?P<year>\d{4})`).
+///
+/// When `pattern`/`idx`/`flags` are literals, `invoke_with_args` compiles the
+/// `Regex` once up front and runs a monomorphic per-array loop; only when
+/// `pattern` or `idx` is itself a column does it fall back to the slower
+/// per-row path, which re-resolves them and looks the `Regex` up in `cache`
+/// on every row. `flags` must always be a literal; a `flags` column is an
+/// error.
+#[derive(Debug)]
+struct RegexpExtract {
+    signature: Signature,
+    cache: RegexCache,
+}
+
+impl RegexpExtract {
+    fn new() -> Self {
+        Self {
+            signature: regexp_extract_signature(),
+            cache: RegexCache::default(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpExtract {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(match &arg_types[0] {
+            DataType::LargeUtf8 => DataType::LargeUtf8,
+            DataType::Utf8View => DataType::Utf8View,
+            _ => DataType::Utf8,
+        })
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let ScalarFunctionArgs {
+            args, number_rows, ..
+        } = args;
+
+        if args.len() != 3 && args.len() != 4 {
+            return Err(DataFusionError::Execution(
+                "regexp_extract expects 3 or 4 arguments: (str, pattern, idx[, flags])".into(),
+            ));
+        }
+
+        let s = &args[0];
+        let pattern = &args[1];
+        let idx = &args[2];
+        let flags = match args.get(3) {
+            Some(flags) => scalar_str(flags, "flags")?,
+            None => String::new(),
+        };
+
+        // Row-wise pattern/idx lookup is only needed when either argument
+        // is an array; a pair of literals is compiled once up front, same
+        // as before this array support was added.
+        let dynamic =
+            matches!(pattern, ColumnarValue::Array(_)) || matches!(idx, ColumnarValue::Array(_));
+
+        let out_arr: ArrayRef = if dynamic {
             let arr = match s {
-                ColumnarValue::Array(arr) => arr
-                    .as_any()
-                    .downcast_ref::<StringArray>()
-                    .ok_or_else(|| {
-                        DataFusionError::Execution(
-                            "str must be a Utf8 array in this implementation".into(),
-                        )
-                    })?,
+                ColumnarValue::Array(arr) => arr,
                 _ => {
                     return Err(DataFusionError::Execution(
                         "str must be an array in this implementation".into(),
@@ -118,44 +351,399 @@ pub fn regexp_extract_udf() -> ScalarUDF {
                 }
             };
 
-            let mut out: Vec<Option<String>> = Vec::with_capacity(arr.len());
-            for i in 0..arr.len() {
-                if arr.is_null(i) {
-                    out.push(None);
-                } else {
-                    let s = arr.value(i);
-                    out.push(Some(extract_one(&re, s, idx_usize)?));
+            match arr.data_type() {
+                DataType::Utf8 => Arc::new(extract_generic_dynamic::<i32>(
+                    arr,
+                    pattern,
+                    idx,
+                    &flags,
+                    &self.cache,
+                )?),
+                DataType::LargeUtf8 => Arc::new(extract_generic_dynamic::<i64>(
+                    arr,
+                    pattern,
+                    idx,
+                    &flags,
+                    &self.cache,
+                )?),
+                DataType::Utf8View => {
+                    Arc::new(extract_view_dynamic(arr, pattern, idx, &flags, &self.cache)?)
+                }
+                other => {
+                    return Err(DataFusionError::Execution(format!(
+                        "str must be a Utf8 array, LargeUtf8 array, or Utf8View array in this implementation, got {other:?}"
+                    )))
                 }
             }
+        } else {
+            let pattern = scalar_str(pattern, "pattern")?;
+            let group = scalar_group_ref(idx)?;
+            let re = self.cache.get(&pattern, &flags)?;
 
-            let out_arr: ArrayRef = Arc::new(StringArray::from(out));
-            Ok(ColumnarValue::Array(out_arr))
-        })
+            let arr = match s {
+                ColumnarValue::Array(arr) => arr,
+                _ => {
+                    return Err(DataFusionError::Execution(
+                        "str must be an array in this implementation".into(),
+                    ))
+                }
+            };
+
+            match arr.data_type() {
+                DataType::Utf8 => Arc::new(extract_generic::<i32>(arr, &re, &group)?),
+                DataType::LargeUtf8 => Arc::new(extract_generic::<i64>(arr, &re, &group)?),
+                DataType::Utf8View => Arc::new(extract_view(arr, &re, &group)?),
+                other => {
+                    return Err(DataFusionError::Execution(format!(
+                        "str must be a Utf8 array, LargeUtf8 array, or Utf8View array in this implementation, got {other:?}"
+                    )))
+                }
+            }
+        };
+
+        if out_arr.len() != number_rows {
+            return Err(DataFusionError::Execution(format!(
+                "regexp_extract produced {} rows but the plan expected {number_rows}",
+                out_arr.len()
+            )));
+        }
+
+        Ok(ColumnarValue::Array(out_arr))
+    }
+}
+
+/// Create and return a DataFusion ScalarUDF implementing Spark-like regexp_extract.
+pub fn regexp_extract_udf() -> ScalarUDF {
+    ScalarUDF::new_from_impl(RegexpExtract::new())
+}
+
+/// Run `extract_one` over a `Utf8`/`LargeUtf8` array, producing the same offset
+/// layout on the way out.
+fn extract_generic<O: OffsetSizeTrait>(
+    arr: &ArrayRef,
+    re: &Regex,
+    group: &GroupRef,
+) -> Result<GenericStringArray<O>> {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<GenericStringArray<O>>()
+        .ok_or_else(|| DataFusionError::Execution("str array has an unexpected layout".into()))?;
+
+    let mut builder = GenericStringBuilder::<O>::with_capacity(arr.len(), 0);
+    for i in 0..arr.len() {
+        if arr.is_null(i) {
+            builder.append_null();
+        } else {
+            builder.append_value(extract_one(re, arr.value(i), group)?);
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Run `extract_one` over a `Utf8View` array.
+fn extract_view(arr: &ArrayRef, re: &Regex, group: &GroupRef) -> Result<StringViewArray> {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StringViewArray>()
+        .ok_or_else(|| DataFusionError::Execution("str array has an unexpected layout".into()))?;
+
+    let mut builder = StringViewBuilder::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        if arr.is_null(i) {
+            builder.append_null();
+        } else {
+            builder.append_value(extract_one(re, arr.value(i), group)?);
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Like `extract_generic`, but re-resolves `pattern`/`idx` per row since at
+/// least one of them is an array rather than a literal.
+fn extract_generic_dynamic<O: OffsetSizeTrait>(
+    arr: &ArrayRef,
+    pattern: &ColumnarValue,
+    idx: &ColumnarValue,
+    flags: &str,
+    cache: &RegexCache,
+) -> Result<GenericStringArray<O>> {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<GenericStringArray<O>>()
+        .ok_or_else(|| DataFusionError::Execution("str array has an unexpected layout".into()))?;
+
+    let mut builder = GenericStringBuilder::<O>::with_capacity(arr.len(), 0);
+    for i in 0..arr.len() {
+        if arr.is_null(i) {
+            builder.append_null();
+            continue;
+        }
+        match extract_one_dynamic(arr.value(i), pattern, idx, flags, i, cache)? {
+            Some(value) => builder.append_value(value),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Like `extract_view`, but re-resolves `pattern`/`idx` per row since at
+/// least one of them is an array rather than a literal.
+fn extract_view_dynamic(
+    arr: &ArrayRef,
+    pattern: &ColumnarValue,
+    idx: &ColumnarValue,
+    flags: &str,
+    cache: &RegexCache,
+) -> Result<StringViewArray> {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StringViewArray>()
+        .ok_or_else(|| DataFusionError::Execution("str array has an unexpected layout".into()))?;
+
+    let mut builder = StringViewBuilder::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        if arr.is_null(i) {
+            builder.append_null();
+            continue;
+        }
+        match extract_one_dynamic(arr.value(i), pattern, idx, flags, i, cache)? {
+            Some(value) => builder.append_value(value),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Resolve this row's pattern/idx and run the regex, returning `Ok(None)`
+/// whenever the pattern or idx is NULL for this row.
+fn extract_one_dynamic(
+    s: &str,
+    pattern: &ColumnarValue,
+    idx: &ColumnarValue,
+    flags: &str,
+    i: usize,
+    cache: &RegexCache,
+) -> Result<Option<String>> {
+    let Some(pattern) = row_pattern(pattern, i)? else {
+        return Ok(None);
+    };
+    let Some(group) = row_group_ref(idx, i)? else {
+        return Ok(None);
+    };
+    let re = cache.get(&pattern, flags)?;
+    Ok(Some(extract_one(&re, s, &group)?))
+}
+
+fn extract_one(re: &Regex, s: &str, group: &GroupRef) -> Result<String> {
+    let Some(caps) = re.captures(s) else {
+        return Ok(String::new());
     };
 
-    create_udf(
-        "regexp_extract",
-        vec![DataType::Utf8, DataType::Utf8, DataType::Int64],
-        DataType::Utf8,
+    match group {
+        GroupRef::Number(idx) => {
+            let idx = *idx as usize;
+            // caps.len() includes group 0 (whole match)
+            if idx >= caps.len() {
+                return Err(DataFusionError::Execution(format!(
+                    "regexp_extract group index {idx} out of range; pattern has {} groups",
+                    caps.len() - 1
+                )));
+            }
+            Ok(caps
+                .get(idx)
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_else(|| "".to_string()))
+        }
+        GroupRef::Name(name) => {
+            if let Some(m) = caps.name(name) {
+                Ok(m.as_str().to_string())
+            } else if re.capture_names().flatten().any(|n| n == name) {
+                Ok("".to_string())
+            } else {
+                Err(DataFusionError::Execution(format!(
+                    "regexp_extract: no capture group named {name:?} in pattern"
+                )))
+            }
+        }
+    }
+}
+
+fn regexp_extract_all_signature() -> Signature {
+    Signature::one_of(
+        vec![
+            TypeSignature::Exact(vec![DataType::Utf8, DataType::Utf8, DataType::Int64]),
+            TypeSignature::Exact(vec![DataType::LargeUtf8, DataType::Utf8, DataType::Int64]),
+            TypeSignature::Exact(vec![DataType::Utf8View, DataType::Utf8, DataType::Int64]),
+        ],
         Volatility::Immutable,
-        fun,
     )
 }
 
-fn extract_one(re: &Regex, s: &str, idx: usize) -> Result<String> {
-    if let Some(caps) = re.captures(s) {
+/// Spark-like `regexp_extract_all(str, pattern, idx)`, returning every
+/// non-overlapping match's group `idx` as a `List(Utf8)` instead of just the
+/// first match. `pattern` and `idx` are scalar-only, so the `Regex` is always
+/// compiled once up front.
+#[derive(Debug)]
+struct RegexpExtractAll {
+    signature: Signature,
+    cache: RegexCache,
+}
+
+impl RegexpExtractAll {
+    fn new() -> Self {
+        Self {
+            signature: regexp_extract_all_signature(),
+            cache: RegexCache::default(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpExtractAll {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract_all"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::List(Arc::new(Field::new(
+            "item",
+            DataType::Utf8,
+            true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let ScalarFunctionArgs {
+            args, number_rows, ..
+        } = args;
+
+        if args.len() != 3 {
+            return Err(DataFusionError::Execution(
+                "regexp_extract_all expects 3 arguments: (str, pattern, idx)".into(),
+            ));
+        }
+
+        let s = &args[0];
+        let pattern = scalar_str(&args[1], "pattern")?;
+        let idx = scalar_i64(&args[2], "idx")?;
+
+        if idx < 0 {
+            return Err(DataFusionError::Execution(format!(
+                "regexp_extract_all group index must be non-negative, got {idx}"
+            )));
+        }
+        let idx_usize: usize = idx as usize;
+
+        let re = self.cache.get(&pattern, "")?;
+
+        let arr = match s {
+            ColumnarValue::Array(arr) => arr,
+            _ => {
+                return Err(DataFusionError::Execution(
+                    "str must be an array in this implementation".into(),
+                ))
+            }
+        };
+
+        let out: ListArray = match arr.data_type() {
+            DataType::Utf8 => extract_all_generic::<i32>(arr, &re, idx_usize)?,
+            DataType::LargeUtf8 => extract_all_generic::<i64>(arr, &re, idx_usize)?,
+            DataType::Utf8View => extract_all_view(arr, &re, idx_usize)?,
+            other => {
+                return Err(DataFusionError::Execution(format!(
+                    "str must be a Utf8 array, LargeUtf8 array, or Utf8View array in this implementation, got {other:?}"
+                )))
+            }
+        };
+
+        if out.len() != number_rows {
+            return Err(DataFusionError::Execution(format!(
+                "regexp_extract_all produced {} rows but the plan expected {number_rows}",
+                out.len()
+            )));
+        }
+
+        Ok(ColumnarValue::Array(Arc::new(out)))
+    }
+}
+
+/// Create and return a DataFusion ScalarUDF implementing Spark-like
+/// `regexp_extract_all(str, pattern, idx)`.
+pub fn regexp_extract_all_udf() -> ScalarUDF {
+    ScalarUDF::new_from_impl(RegexpExtractAll::new())
+}
+
+/// Run `extract_all_one` over a `Utf8`/`LargeUtf8` array.
+fn extract_all_generic<O: OffsetSizeTrait>(
+    arr: &ArrayRef,
+    re: &Regex,
+    idx: usize,
+) -> Result<ListArray> {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<GenericStringArray<O>>()
+        .ok_or_else(|| DataFusionError::Execution("str array has an unexpected layout".into()))?;
+
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for i in 0..arr.len() {
+        if arr.is_null(i) {
+            builder.append(false);
+            continue;
+        }
+        for m in extract_all_one(re, arr.value(i), idx)? {
+            builder.values().append_value(m);
+        }
+        builder.append(true);
+    }
+    Ok(builder.finish())
+}
+
+/// Run `extract_all_one` over a `Utf8View` array.
+fn extract_all_view(arr: &ArrayRef, re: &Regex, idx: usize) -> Result<ListArray> {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StringViewArray>()
+        .ok_or_else(|| DataFusionError::Execution("str array has an unexpected layout".into()))?;
+
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for i in 0..arr.len() {
+        if arr.is_null(i) {
+            builder.append(false);
+            continue;
+        }
+        for m in extract_all_one(re, arr.value(i), idx)? {
+            builder.values().append_value(m);
+        }
+        builder.append(true);
+    }
+    Ok(builder.finish())
+}
+
+/// Collect group `idx` from every non-overlapping match of `re` in `s`; an
+/// empty `Vec` means no matches, mirroring `regexp_extract`'s empty-string
+/// convention for the single-match case.
+fn extract_all_one(re: &Regex, s: &str, idx: usize) -> Result<Vec<String>> {
+    let mut out = Vec::new();
+    for caps in re.captures_iter(s) {
         // caps.len() includes group 0 (whole match)
         if idx >= caps.len() {
             return Err(DataFusionError::Execution(format!(
-                "regexp_extract group index {idx} out of range; pattern has {} groups",
+                "regexp_extract_all group index {idx} out of range; pattern has {} groups",
                 caps.len() - 1
             )));
         }
-        Ok(caps
-            .get(idx)
-            .map(|m| m.as_str().to_string())
-            .unwrap_or_else(|| "".to_string()))
-    } else {
-        Ok("".to_string())
+        out.push(
+            caps.get(idx)
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_else(|| "".to_string()),
+        );
     }
+    Ok(out)
 }
@@ -1,12 +1,15 @@
 use std::sync::Arc;
 
-use arrow::array::{Array, ArrayRef, Int32Array, StringArray};
+use arrow::array::{
+    Array, ArrayRef, Int32Array, Int64Array, LargeStringArray, ListArray, StringArray,
+    StringViewArray,
+};
 use arrow::datatypes::{DataType, Field, FieldRef};
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::ScalarValue;
 use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
 
-use datafusion_regexp_extract::regexp_extract_udf;
+use datafusion_regexp_extract::{regexp_extract_all_udf, regexp_extract_udf};
 
 fn field(name: &str, data_type: DataType, nullable: bool) -> FieldRef {
     Arc::new(Field::new(name, data_type, nullable))
@@ -29,7 +32,28 @@ fn invoke_raw(
     })
 }
 
-fn regexp_extract(
+fn invoke_raw_all(
+    args: Vec<ColumnarValue>,
+    arg_fields: Vec<FieldRef>,
+    number_rows: usize,
+) -> datafusion_common::Result<ColumnarValue> {
+    let udf = regexp_extract_all_udf();
+    let return_field = field(
+        "x",
+        DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+        true,
+    );
+
+    udf.invoke_with_args(ScalarFunctionArgs {
+        args,
+        arg_fields,
+        number_rows,
+        return_field,
+        config_options: Arc::new(ConfigOptions::new()),
+    })
+}
+
+fn regexp_extract_all(
     s: ColumnarValue,
     pattern: &str,
     idx: i64,
@@ -48,6 +72,37 @@ fn regexp_extract(
         ColumnarValue::Scalar(ScalarValue::Utf8(Some(pattern.to_string()))),
         ColumnarValue::Scalar(ScalarValue::Int64(Some(idx))),
     ];
+    invoke_raw_all(args, arg_fields, number_rows)
+}
+
+fn regexp_extract(
+    s: ColumnarValue,
+    pattern: &str,
+    idx: i64,
+) -> datafusion_common::Result<ColumnarValue> {
+    regexp_extract_typed(s, DataType::Utf8, pattern, idx)
+}
+
+fn regexp_extract_typed(
+    s: ColumnarValue,
+    s_type: DataType,
+    pattern: &str,
+    idx: i64,
+) -> datafusion_common::Result<ColumnarValue> {
+    let number_rows = match &s {
+        ColumnarValue::Scalar(_) => 1,
+        ColumnarValue::Array(arr) => arr.len(),
+    };
+    let arg_fields = vec![
+        field("s", s_type, true),
+        field("pattern", DataType::Utf8, true),
+        field("idx", DataType::Int64, true),
+    ];
+    let args = vec![
+        s,
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(pattern.to_string()))),
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(idx))),
+    ];
     invoke_raw(args, arg_fields, number_rows)
 }
 
@@ -221,7 +276,7 @@ fn regexp_extract_errors_on_wrong_arity() {
     )
     .expect_err("wrong number of arguments should fail");
 
-    assert!(err.to_string().contains("expects 3 arguments"));
+    assert!(err.to_string().contains("expects 3 or 4 arguments"));
 }
 
 #[test]
@@ -245,11 +300,12 @@ fn regexp_extract_errors_when_pattern_is_non_utf8_scalar() {
 }
 
 #[test]
-fn regexp_extract_errors_when_pattern_is_array() {
-    let pattern_arr: ArrayRef = Arc::new(StringArray::from(vec![Some("(a)")]));
+fn regexp_extract_errors_when_pattern_array_is_non_utf8() {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc")]));
+    let pattern_arr: ArrayRef = Arc::new(Int64Array::from(vec![1]));
     let err = invoke_raw(
         vec![
-            ColumnarValue::Scalar(ScalarValue::Utf8(Some("abc".to_string()))),
+            ColumnarValue::Array(s),
             ColumnarValue::Array(pattern_arr),
             ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
         ],
@@ -260,18 +316,18 @@ fn regexp_extract_errors_when_pattern_is_array() {
         ],
         1,
     )
-    .expect_err("pattern array should fail");
+    .expect_err("non-Utf8 pattern array should fail");
 
-    assert!(err.to_string().contains("pattern must be a scalar"));
+    assert!(err.to_string().contains("pattern array must be Utf8"));
 }
 
 #[test]
-fn regexp_extract_errors_when_idx_is_non_int64_scalar() {
+fn regexp_extract_errors_when_idx_scalar_has_unsupported_type() {
     let err = invoke_raw(
         vec![
-            ColumnarValue::Scalar(ScalarValue::Utf8(Some("abc".to_string()))),
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![Some("abc")])) as ArrayRef),
             ColumnarValue::Scalar(ScalarValue::Utf8(Some("(a)".to_string()))),
-            ColumnarValue::Scalar(ScalarValue::Utf8(Some("1".to_string()))),
+            ColumnarValue::Scalar(ScalarValue::Float64(Some(1.0))),
         ],
         vec![
             field("s", DataType::Utf8, true),
@@ -280,17 +336,18 @@ fn regexp_extract_errors_when_idx_is_non_int64_scalar() {
         ],
         1,
     )
-    .expect_err("non-int64 idx should fail");
+    .expect_err("non-int64/utf8 idx should fail");
 
-    assert!(err.to_string().contains("idx must be Int64 scalar"));
+    assert!(err.to_string().contains("idx must be an Int64 or Utf8 scalar"));
 }
 
 #[test]
-fn regexp_extract_errors_when_idx_is_array() {
+fn regexp_extract_errors_when_idx_array_has_unsupported_type() {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc")]));
     let idx_arr: ArrayRef = Arc::new(Int32Array::from(vec![1]));
     let err = invoke_raw(
         vec![
-            ColumnarValue::Scalar(ScalarValue::Utf8(Some("abc".to_string()))),
+            ColumnarValue::Array(s),
             ColumnarValue::Scalar(ScalarValue::Utf8(Some("(a)".to_string()))),
             ColumnarValue::Array(idx_arr),
         ],
@@ -301,9 +358,9 @@ fn regexp_extract_errors_when_idx_is_array() {
         ],
         1,
     )
-    .expect_err("idx array should fail");
+    .expect_err("non-Int64/Utf8 idx array should fail");
 
-    assert!(err.to_string().contains("idx must be a scalar"));
+    assert!(err.to_string().contains("idx array must be Int64 or Utf8"));
 }
 
 #[test]
@@ -386,3 +443,390 @@ fn regexp_extract_array_str_nulls_are_accepted() {
 
     assert_eq!(actual, vec![Some("a".to_string()), None, Some("".to_string())]);
 }
+
+#[test]
+fn regexp_extract_large_utf8_returns_large_utf8() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(LargeStringArray::from(vec![Some("abc-123"), None]));
+    let out = regexp_extract_typed(
+        ColumnarValue::Array(s),
+        DataType::LargeUtf8,
+        "([a-z]+)-(\\d+)",
+        2,
+    )?;
+
+    match out {
+        ColumnarValue::Array(arr) => {
+            assert_eq!(arr.data_type(), &DataType::LargeUtf8);
+            let arr = arr
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("regexp_extract should return a LargeUtf8 array for a LargeUtf8 input");
+            assert_eq!(arr.value(0), "123");
+            assert!(arr.is_null(1));
+        }
+        other => panic!("unexpected output: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_with_per_row_pattern_array() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc-123"), Some("2024-07")]));
+    let pattern: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("([a-z]+)-(\\d+)"),
+        Some("(\\d+)-(\\d+)"),
+    ]));
+
+    let out = invoke_raw(
+        vec![
+            ColumnarValue::Array(s),
+            ColumnarValue::Array(pattern),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(2))),
+        ],
+        vec![
+            field("s", DataType::Utf8, true),
+            field("pattern", DataType::Utf8, true),
+            field("idx", DataType::Int64, true),
+        ],
+        2,
+    )?;
+
+    let arr = match out {
+        ColumnarValue::Array(arr) => arr,
+        other => panic!("unexpected output: {other:?}"),
+    };
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("regexp_extract should return Utf8 array");
+    assert_eq!(arr.value(0), "123");
+    assert_eq!(arr.value(1), "07");
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_with_per_row_idx_array() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc-123"), Some("abc-123")]));
+    let idx: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+
+    let out = invoke_raw(
+        vec![
+            ColumnarValue::Array(s),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("([a-z]+)-(\\d+)".to_string()))),
+            ColumnarValue::Array(idx),
+        ],
+        vec![
+            field("s", DataType::Utf8, true),
+            field("pattern", DataType::Utf8, true),
+            field("idx", DataType::Int64, true),
+        ],
+        2,
+    )?;
+
+    let arr = match out {
+        ColumnarValue::Array(arr) => arr,
+        other => panic!("unexpected output: {other:?}"),
+    };
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("regexp_extract should return Utf8 array");
+    assert_eq!(arr.value(0), "abc");
+    assert_eq!(arr.value(1), "123");
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_per_row_null_pattern_or_idx_yields_null_row() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("abc-123"),
+        Some("abc-123"),
+        Some("abc-123"),
+    ]));
+    let pattern: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("([a-z]+)-(\\d+)"),
+        None,
+        Some("([a-z]+)-(\\d+)"),
+    ]));
+    let idx: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), Some(1), None]));
+
+    let out = invoke_raw(
+        vec![
+            ColumnarValue::Array(s),
+            ColumnarValue::Array(pattern),
+            ColumnarValue::Array(idx),
+        ],
+        vec![
+            field("s", DataType::Utf8, true),
+            field("pattern", DataType::Utf8, true),
+            field("idx", DataType::Int64, true),
+        ],
+        3,
+    )?;
+
+    let arr = match out {
+        ColumnarValue::Array(arr) => arr,
+        other => panic!("unexpected output: {other:?}"),
+    };
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("regexp_extract should return Utf8 array");
+    assert_eq!(arr.value(0), "abc");
+    assert!(arr.is_null(1));
+    assert!(arr.is_null(2));
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_utf8_view_returns_utf8_view() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringViewArray::from(vec![Some("xxabcdyy"), Some("no match")]));
+    let out = regexp_extract_typed(ColumnarValue::Array(s), DataType::Utf8View, "(ab)(cd)", 0)?;
+
+    match out {
+        ColumnarValue::Array(arr) => {
+            assert_eq!(arr.data_type(), &DataType::Utf8View);
+            let arr = arr
+                .as_any()
+                .downcast_ref::<StringViewArray>()
+                .expect("regexp_extract should return a Utf8View array for a Utf8View input");
+            assert_eq!(arr.value(0), "abcd");
+            assert_eq!(arr.value(1), "");
+        }
+        other => panic!("unexpected output: {other:?}"),
+    }
+    Ok(())
+}
+
+fn regexp_extract_with_flags(
+    s: ColumnarValue,
+    pattern: &str,
+    idx: i64,
+    flags: &str,
+) -> datafusion_common::Result<ColumnarValue> {
+    let number_rows = match &s {
+        ColumnarValue::Scalar(_) => 1,
+        ColumnarValue::Array(arr) => arr.len(),
+    };
+    let arg_fields = vec![
+        field("s", DataType::Utf8, true),
+        field("pattern", DataType::Utf8, true),
+        field("idx", DataType::Int64, true),
+        field("flags", DataType::Utf8, true),
+    ];
+    let args = vec![
+        s,
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(pattern.to_string()))),
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(idx))),
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(flags.to_string()))),
+    ];
+    invoke_raw(args, arg_fields, number_rows)
+}
+
+#[test]
+fn regexp_extract_case_insensitive_flag() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("ABC-123")]));
+    let out = regexp_extract_with_flags(ColumnarValue::Array(s), "([a-z]+)-(\\d+)", 1, "i")?;
+
+    match out {
+        ColumnarValue::Array(arr) => {
+            let arr = arr
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("regexp_extract should return Utf8 array");
+            assert_eq!(arr.value(0), "ABC");
+        }
+        other => panic!("unexpected output: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_flags_distinguish_cache_entries_for_same_pattern() -> datafusion_common::Result<()>
+{
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("ABC")]));
+    let sensitive =
+        regexp_extract_with_flags(ColumnarValue::Array(s.clone()), "(abc)", 0, "")?;
+    let insensitive = regexp_extract_with_flags(ColumnarValue::Array(s), "(abc)", 0, "i")?;
+
+    let value = |out: ColumnarValue| -> String {
+        match out {
+            ColumnarValue::Array(arr) => arr
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .value(0)
+                .to_string(),
+            other => panic!("unexpected output: {other:?}"),
+        }
+    };
+
+    assert_eq!(value(sensitive), "");
+    assert_eq!(value(insensitive), "ABC");
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_all_collects_every_match() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("a=1, b=2, c=3")]));
+    let out = regexp_extract_all(ColumnarValue::Array(s), "([a-z])=(\\d)", 2)?;
+
+    let arr = match out {
+        ColumnarValue::Array(arr) => arr,
+        other => panic!("unexpected output: {other:?}"),
+    };
+    let list = arr
+        .as_any()
+        .downcast_ref::<ListArray>()
+        .expect("regexp_extract_all should return a List array");
+    let row0 = list.value(0);
+    let row0 = row0
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("list child should be Utf8");
+    let values: Vec<&str> = (0..row0.len()).map(|i| row0.value(i)).collect();
+    assert_eq!(values, vec!["1", "2", "3"]);
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_all_returns_empty_list_when_no_matches() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("no digits here")]));
+    let out = regexp_extract_all(ColumnarValue::Array(s), "(\\d+)", 1)?;
+
+    let arr = match out {
+        ColumnarValue::Array(arr) => arr,
+        other => panic!("unexpected output: {other:?}"),
+    };
+    let list = arr
+        .as_any()
+        .downcast_ref::<ListArray>()
+        .expect("regexp_extract_all should return a List array");
+    assert_eq!(list.value(0).len(), 0);
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_all_null_row_is_null_list() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("a=1"), None]));
+    let out = regexp_extract_all(ColumnarValue::Array(s), "([a-z])=(\\d)", 2)?;
+
+    let arr = match out {
+        ColumnarValue::Array(arr) => arr,
+        other => panic!("unexpected output: {other:?}"),
+    };
+    let list = arr
+        .as_any()
+        .downcast_ref::<ListArray>()
+        .expect("regexp_extract_all should return a List array");
+    assert!(list.is_null(1));
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_all_errors_on_out_of_range_group() {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc")]));
+    let err = regexp_extract_all(ColumnarValue::Array(s), "(a)", 2)
+        .expect_err("out-of-range group index should fail");
+
+    assert!(err.to_string().contains("out of range"));
+}
+
+#[test]
+fn regexp_extract_errors_on_unknown_flag_character() {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc")]));
+    let err = regexp_extract_with_flags(ColumnarValue::Array(s), "(abc)", 0, "z")
+        .expect_err("unknown flag character should fail");
+
+    assert!(err.to_string().contains("unknown flag character"));
+}
+
+fn regexp_extract_named(
+    s: ColumnarValue,
+    pattern: &str,
+    group_name: &str,
+) -> datafusion_common::Result<ColumnarValue> {
+    let number_rows = match &s {
+        ColumnarValue::Scalar(_) => 1,
+        ColumnarValue::Array(arr) => arr.len(),
+    };
+    let arg_fields = vec![
+        field("s", DataType::Utf8, true),
+        field("pattern", DataType::Utf8, true),
+        field("idx", DataType::Utf8, true),
+    ];
+    let args = vec![
+        s,
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(pattern.to_string()))),
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(group_name.to_string()))),
+    ];
+    invoke_raw(args, arg_fields, number_rows)
+}
+
+#[test]
+fn regexp_extract_named_group() -> datafusion_common::Result<()> {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("2024-07-26")]));
+    let out = regexp_extract_named(
+        ColumnarValue::Array(s),
+        r"(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})",
+        "month",
+    )?;
+
+    match out {
+        ColumnarValue::Array(arr) => {
+            let arr = arr
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("regexp_extract should return Utf8 array");
+            assert_eq!(arr.value(0), "07");
+        }
+        other => panic!("unexpected output: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_named_group_not_participating_is_empty_string() -> datafusion_common::Result<()>
+{
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc")]));
+    let out = regexp_extract_named(ColumnarValue::Array(s), "(?P<word>abc)|(?P<num>\\d+)", "num")?;
+
+    match out {
+        ColumnarValue::Array(arr) => {
+            let arr = arr
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("regexp_extract should return Utf8 array");
+            assert_eq!(arr.value(0), "");
+        }
+        other => panic!("unexpected output: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn regexp_extract_errors_on_unknown_group_name() {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("abc")]));
+    let err = regexp_extract_named(ColumnarValue::Array(s), "(?P<word>abc)", "nope")
+        .expect_err("unknown group name should fail");
+
+    assert!(err.to_string().contains("no capture group named"));
+}
+
+#[test]
+fn regexp_extract_errors_when_number_rows_mismatches_output() {
+    let s: ArrayRef = Arc::new(StringArray::from(vec![Some("2024-07-26"), Some("2023-01-01")]));
+    let args = vec![
+        ColumnarValue::Array(s),
+        ColumnarValue::Scalar(ScalarValue::Utf8(Some(r"(\d+)-".to_string()))),
+        ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+    ];
+    let arg_fields = vec![
+        field("s", DataType::Utf8, true),
+        field("pattern", DataType::Utf8, true),
+        field("idx", DataType::Int64, true),
+    ];
+
+    let err = invoke_raw(args, arg_fields, 3).expect_err("wrong number_rows should fail");
+    assert!(err.to_string().contains("expected 3"));
+}